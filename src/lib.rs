@@ -1,11 +1,11 @@
 #![warn(clippy::all, clippy::pedantic)]
 
-//! `try-continue` provides one method, [`try_continue`](`TryContinue::try_continue`),
-//! which allows you to work with iterators of type `Result<T, _>`, as if they were
-//! simply iterators of type `T`. this is is implemented for all iterators providing
-//! a `Result`. This is particularly useful if you need to map to a fallible function,
-//! and would like to continue using the iterator API to process the elements, but still
-//! know if the mapped function fails.
+//! `try-continue` provides [`try_continue`](`TryContinue::try_continue`) and a handful of
+//! related methods, which allow you to work with iterators of type `Result<T, _>` (or
+//! `Option<T>`), as if they were simply iterators of type `T`. this is is implemented for all
+//! iterators providing a `Result`. This is particularly useful if you need to map to a fallible
+//! function, and would like to continue using the iterator API to process the elements, but
+//! still know if the mapped function fails.
 //!
 //! For instance, consider a simple parser where you are provided a list of integers as
 //! strings, and you would like to count all the strings that hold even numbers. If you
@@ -33,6 +33,58 @@
 //! let num_evens_bad_result = count_even_number_strings(&vec!["1", "2", "three", "-4", "28"]);
 //! assert!(num_evens_bad_result.is_err());
 //! ```
+//!
+//! Beyond the basic case above, this crate also provides:
+//!
+//! - [`TryContinueOption::try_continue`], the same short-circuiting behavior for iterators of
+//!   `Option<T>` rather than `Result<T, _>`.
+//! - [`TryMapContinue::try_map_continue`], which fuses a fallible `map` step into the call so
+//!   you don't need to write `.map(...)` yourself beforehand.
+//! - [`TryContinue::try_continue_all`], which collects every `Err` instead of stopping at the
+//!   first one, useful for validation workloads.
+//! - [`TryContinue::try_continue_resumable`], which on failure hands back both the error and
+//!   the remaining iterator, so processing can be resumed or the unconsumed tail inspected.
+
+use std::iter::FusedIterator;
+use std::ops::ControlFlow;
+
+/// An internal abstraction over types which can either succeed with an [`Output`](TryOutcome::Output)
+/// or short-circuit with a [`Residual`](TryOutcome::Residual), such as `Result<T, E>` and `Option<T>`.
+/// This lets [`TryContinueIter`] be written once and shared between both.
+#[doc(hidden)]
+pub trait TryOutcome {
+    /// The value produced when `self` represents success.
+    type Output;
+    /// The value captured when `self` represents failure.
+    type Residual;
+
+    /// Splits `self` into its output, or the residual to short-circuit with.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+impl<T, E> TryOutcome for Result<T, E> {
+    type Output = T;
+    type Residual = E;
+
+    fn branch(self) -> ControlFlow<E, T> {
+        match self {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(err) => ControlFlow::Break(err),
+        }
+    }
+}
+
+impl<T> TryOutcome for Option<T> {
+    type Output = T;
+    type Residual = ();
+
+    fn branch(self) -> ControlFlow<(), T> {
+        match self {
+            Some(value) => ControlFlow::Continue(value),
+            None => ControlFlow::Break(()),
+        }
+    }
+}
 
 /// Provides the [`TryContinue::try_continue`] method, which allows use of the
 /// iterator API after mapping to fallible functions.
@@ -80,40 +132,287 @@ pub trait TryContinue<T, E>: Iterator<Item = Result<T, E>> {
     {
         let mut iter = TryContinueIter::new(self);
         let iteration_output = f(&mut iter);
-        iter.err.map_or(Ok(iteration_output), Err)
+        iter.residual.map_or(Ok(iteration_output), Err)
+    }
+
+    /// Like [`TryContinue::try_continue`], but rather than stopping at the first `Err`,
+    /// skips over failed elements and keeps feeding the remaining `Ok` values to the closure.
+    /// Every `Err` encountered along the way is collected, so this is useful for validation-style
+    /// workloads where you want to report every malformed element in one pass, rather than just
+    /// the first.
+    ///
+    /// # Errors
+    /// Returns every `E` that was encountered, in encounter order, as long as at least one
+    /// element failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use try_continue::TryContinue;
+    ///
+    /// let elements = vec!["1", "two", "3", "four"];
+    /// let total = elements
+    ///     .into_iter()
+    ///     .map(str::parse::<u8>)
+    ///     .try_continue_all(|iter| iter.sum::<u8>());
+    ///
+    /// assert_eq!(2, total.unwrap_err().len());
+    /// ```
+    fn try_continue_all<F, R>(self, f: F) -> Result<R, Vec<E>>
+    where
+        Self: Sized,
+        F: FnOnce(&mut TryContinueAllIter<Self, E>) -> R,
+    {
+        let mut iter = TryContinueAllIter::new(self);
+        let iteration_output = f(&mut iter);
+        if iter.errors.is_empty() {
+            Ok(iteration_output)
+        } else {
+            Err(iter.errors)
+        }
+    }
+
+    /// Like [`TryContinue::try_continue`], but rather than discarding the iterator once an
+    /// `Err` is captured, the `Err` variant carries both the error and the underlying iterator,
+    /// with the failing element already taken from it (when iterating forwards, it is positioned
+    /// just after the failing element; an `Err` hit through `next_back` is taken from the back
+    /// instead). This turns the short-circuit into a
+    /// checkpoint: a caller can log the failure and resume processing the tail, or simply
+    /// inspect how far iteration got, rather than losing access to the remaining elements.
+    ///
+    /// # Errors
+    /// Returns a [`Resumable`] if the given function's iterator hits an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use try_continue::TryContinue;
+    ///
+    /// let elements = vec!["1", "two", "3"];
+    /// let res = elements
+    ///     .into_iter()
+    ///     .map(str::parse::<u8>)
+    ///     .try_continue_resumable(|iter| iter.collect::<Vec<u8>>());
+    ///
+    /// let resumable = res.unwrap_err();
+    /// let remaining = resumable.iter.collect::<Vec<_>>();
+    /// assert_eq!(vec![Ok(3)], remaining);
+    /// ```
+    fn try_continue_resumable<F, R>(self, f: F) -> Result<R, Resumable<Self, E>>
+    where
+        Self: Sized,
+        F: FnOnce(&mut TryContinueIter<Self, E>) -> R,
+    {
+        let mut iter = TryContinueIter::new(self);
+        let iteration_output = f(&mut iter);
+        match iter.residual {
+            Some(error) => Err(Resumable {
+                error,
+                iter: iter.iter,
+            }),
+            None => Ok(iteration_output),
+        }
     }
 }
 
 impl<T, E, I: Iterator<Item = Result<T, E>>> TryContinue<T, E> for I {}
 
-/// The iterator produced by [`TryContinue::try_continue`], which is passed to
-/// the given closure. See its docs for more information.
-pub struct TryContinueIter<I, E> {
+/// The error variant returned by [`TryContinue::try_continue_resumable`]. Carries the error
+/// that caused iteration to stop, along with the underlying iterator `I`, from which the failing
+/// element has already been taken, so that a caller can resume processing what is left.
+pub struct Resumable<I, E> {
+    /// The error that caused iteration to stop.
+    pub error: E,
+    /// The underlying iterator, with the failing element already taken from it.
+    pub iter: I,
+}
+
+/// The iterator produced by [`TryContinue::try_continue_all`], which is passed to the given
+/// closure. Unlike [`TryContinueIter`], it does not stop at the first `Err` it encounters;
+/// instead it skips the failed element, records the error, and continues yielding the
+/// remaining `Ok` values.
+pub struct TryContinueAllIter<I, E> {
     iter: I,
-    err: Option<E>,
+    errors: Vec<E>,
 }
 
-impl<I, E> TryContinueIter<I, E> {
+impl<I, E> TryContinueAllIter<I, E> {
     fn new(iter: I) -> Self {
-        Self { iter, err: None }
+        Self {
+            iter,
+            errors: Vec::new(),
+        }
     }
 }
 
-impl<T, E, I: Iterator<Item = Result<T, E>>> Iterator for TryContinueIter<I, E> {
+impl<T, E, I: Iterator<Item = Result<T, E>>> Iterator for TryContinueAllIter<I, E> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let res = self.iter.next()?;
-        match res {
-            Ok(value) => Some(value),
-            Err(err) => {
-                self.err = Some(err);
+        loop {
+            match self.iter.next()? {
+                Ok(value) => return Some(value),
+                Err(err) => self.errors.push(err),
+            }
+        }
+    }
+}
+
+/// Provides the [`TryMapContinue::try_map_continue`] method, which fuses a fallible
+/// [`map`](Iterator::map) step into [`TryContinue::try_continue`].
+pub trait TryMapContinue<T>: Iterator<Item = T> {
+    /// Equivalent to `self.map(map).try_continue(f)`, but saves having to write the
+    /// intermediate `map` call yourself. The first closure fallibly maps each element,
+    /// and the second receives an iterator of the successfully mapped values, short-circuiting
+    /// and capturing the error on the first failed mapping.
+    ///
+    /// # Errors
+    /// The `Result` will only return an error if `map` returns one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use try_continue::TryMapContinue;
+    ///
+    /// let elements = vec!["1", "2", "3", "4"];
+    /// let total = elements
+    ///     .into_iter()
+    ///     .try_map_continue(str::parse::<u8>, |iter| iter.sum());
+    ///
+    /// assert_eq!(10_u8, total.unwrap());
+    /// ```
+    ///
+    /// ```
+    /// use try_continue::TryMapContinue;
+    ///
+    /// let elements = vec!["1", "2", "three", "4"];
+    /// let total = elements
+    ///     .into_iter()
+    ///     .try_map_continue(str::parse::<u8>, |iter| iter.sum::<u8>());
+    ///
+    /// assert!(total.is_err());
+    /// ```
+    fn try_map_continue<U, E, M, F, R>(self, map: M, f: F) -> Result<R, E>
+    where
+        Self: Sized,
+        M: FnMut(T) -> Result<U, E>,
+        F: FnOnce(&mut TryContinueIter<std::iter::Map<Self, M>, E>) -> R,
+    {
+        self.map(map).try_continue(f)
+    }
+}
+
+impl<T, I: Iterator<Item = T>> TryMapContinue<T> for I {}
+
+/// Provides the [`TryContinueOption::try_continue`] method, the `Option`-based counterpart to
+/// [`TryContinue::try_continue`]. This is useful when you have an iterator of `Option<T>`, and
+/// would like to continue treating it as an iterator of `T`, short-circuiting to `None` as soon
+/// as a `None` element is produced.
+pub trait TryContinueOption<T>: Iterator<Item = Option<T>> {
+    /// Allows one to continue processing an iterator of `Option<T>`, as if it were simply
+    /// an iterator of `T`, provided that all of the elements are `Some`. The iterator will
+    /// short-circuit if a `None` element is encountered. See [`TryContinue::try_continue`]
+    /// for the `Result`-based equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use try_continue::TryContinueOption;
+    ///
+    /// let elements = vec![Some(1), Some(2), Some(3)];
+    /// let total = elements.into_iter().try_continue(|iter| iter.sum::<i32>());
+    ///
+    /// assert_eq!(Some(6), total);
+    ///
+    /// let elements = vec![Some(1), None, Some(3)];
+    /// let total = elements.into_iter().try_continue(|iter| iter.sum::<i32>());
+    ///
+    /// assert_eq!(None, total);
+    /// ```
+    fn try_continue<F, R>(self, f: F) -> Option<R>
+    where
+        Self: Sized,
+        F: FnOnce(&mut TryContinueIter<Self, ()>) -> R,
+    {
+        let mut iter = TryContinueIter::new(self);
+        let iteration_output = f(&mut iter);
+        iter.residual.map_or(Some(iteration_output), |()| None)
+    }
+}
+
+impl<T, I: Iterator<Item = Option<T>>> TryContinueOption<T> for I {}
+
+/// The iterator produced by [`TryContinue::try_continue`] and [`TryContinueOption::try_continue`],
+/// which is passed to the given closure. See their docs for more information.
+///
+/// Once a residual (an `Err` or `None`) has been captured, the iterator is exhausted for good:
+/// further calls to `next`/`next_back` always return `None`, even if the underlying iterator
+/// still has elements left.
+pub struct TryContinueIter<I, Residual> {
+    iter: I,
+    residual: Option<Residual>,
+}
+
+impl<I, Residual> TryContinueIter<I, Residual> {
+    fn new(iter: I) -> Self {
+        Self { iter, residual: None }
+    }
+}
+
+impl<I, O: TryOutcome> Iterator for TryContinueIter<I, O::Residual>
+where
+    I: Iterator<Item = O>,
+{
+    type Item = O::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.residual.is_some() {
+            return None;
+        }
+
+        let item = self.iter.next()?;
+        match item.branch() {
+            ControlFlow::Continue(value) => Some(value),
+            ControlFlow::Break(residual) => {
+                self.residual = Some(residual);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The lower bound can't be trusted, since any remaining element may be a residual
+        // that ends iteration early.
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, O: TryOutcome> DoubleEndedIterator for TryContinueIter<I, O::Residual>
+where
+    I: DoubleEndedIterator<Item = O>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.residual.is_some() {
+            return None;
+        }
+
+        let item = self.iter.next_back()?;
+        match item.branch() {
+            ControlFlow::Continue(value) => Some(value),
+            ControlFlow::Break(residual) => {
+                self.residual = Some(residual);
                 None
             }
         }
     }
 }
 
+impl<I, O: TryOutcome> FusedIterator for TryContinueIter<I, O::Residual> where
+    I: FusedIterator<Item = O>
+{
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,4 +477,149 @@ mod tests {
             res.unwrap()
         );
     }
+
+    #[test]
+    fn test_size_hint_drops_lower_bound_to_zero() {
+        let items = vec![1, 2, 3];
+        let res: Result<(), TestError> = items
+            .into_iter()
+            .map(|x| -> Result<i32, TestError> { Ok(x) })
+            .try_continue(|iter| {
+                assert_eq!((0, Some(3)), iter.size_hint());
+            });
+
+        res.unwrap();
+    }
+
+    #[test]
+    fn test_supports_reverse_iteration() {
+        let items = vec![1, 2, 3];
+        let res = items
+            .into_iter()
+            .map(|x| -> Result<i32, TestError> { Ok(x) })
+            .try_continue(|iter| iter.rev().collect::<Vec<i32>>());
+
+        assert_eq!(vec![3, 2, 1], res.unwrap());
+    }
+
+    #[test]
+    fn test_is_exhausted_for_good_after_an_error() {
+        let items = vec![1, 2, 3];
+        let res = items
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| -> Result<i32, TestError> {
+                if i == 1 {
+                    Err(TestError("oh no this is bad"))
+                } else {
+                    Ok(x)
+                }
+            })
+            .try_continue(|iter| {
+                assert_eq!(Some(1), iter.next());
+                assert_eq!(None, iter.next());
+                assert_eq!(None, iter.next());
+            });
+
+        assert_eq!(TestError("oh no this is bad"), res.unwrap_err());
+    }
+
+    #[test]
+    fn test_try_continue_resumable_carries_error_and_remaining_iterator() {
+        let items = vec![1, 2, 3];
+        let res: Result<i32, Resumable<_, TestError>> = items
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| -> Result<i32, TestError> {
+                if i == 1 {
+                    Err(TestError("oh no this is bad"))
+                } else {
+                    Ok(x)
+                }
+            })
+            .try_continue_resumable(|iter| iter.sum());
+
+        let resumable = res.unwrap_err();
+        assert_eq!(TestError("oh no this is bad"), resumable.error);
+        assert_eq!(vec![Ok(3)], resumable.iter.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_try_continue_resumable_succeeds_without_error() {
+        let items = vec![1, 2, 3];
+        let res = items
+            .into_iter()
+            .map(|x| -> Result<i32, TestError> { Ok(x) })
+            .try_continue_resumable(|iter| iter.sum::<i32>());
+
+        assert_eq!(6, res.unwrap_or(-1));
+    }
+
+    #[test]
+    fn test_try_continue_all_collects_every_error() {
+        let items = vec![1, 2, 3, 4];
+        let res: Result<i32, Vec<TestError>> = items
+            .into_iter()
+            .map(|x| -> Result<i32, TestError> {
+                if x % 2 == 0 {
+                    Err(TestError("even numbers are bad"))
+                } else {
+                    Ok(x)
+                }
+            })
+            .try_continue_all(|iter| iter.sum());
+
+        assert_eq!(
+            vec![TestError("even numbers are bad"), TestError("even numbers are bad")],
+            res.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_try_continue_all_succeeds_when_no_errors() {
+        let items = vec![1, 2, 3];
+        let res = items
+            .into_iter()
+            .map(|x| -> Result<i32, TestError> { Ok(x) })
+            .try_continue_all(|iter| iter.sum());
+
+        assert_eq!(6, res.unwrap());
+    }
+
+    #[test]
+    fn test_try_map_continue_wraps_iterator() {
+        let items = vec![1, 2, 3];
+        let res = items
+            .into_iter()
+            .try_map_continue(|x| -> Result<i32, TestError> { Ok(x) }, |iter| iter.sum());
+
+        assert_eq!(6, res.unwrap());
+    }
+
+    #[test]
+    fn test_try_map_continue_bubbles_out_error() {
+        let items = vec![1, 2, 3];
+        let res = items.into_iter().try_map_continue(
+            |_| -> Result<i32, TestError> { Err(TestError("oh no this is bad")) },
+            |iter| iter.count(),
+        );
+
+        assert_eq!(TestError("oh no this is bad"), res.unwrap_err());
+    }
+
+    #[test]
+    fn test_option_can_wrap_iterator() {
+        let items = vec![Some(1), Some(2), Some(3)];
+        let res = items.into_iter().try_continue(|iter| iter.sum::<i32>());
+
+        assert_eq!(Some(6), res);
+    }
+
+    #[test]
+    fn test_option_bubbles_out_none() {
+        let items = vec![Some(1), None, Some(3)];
+        let res = items.into_iter().try_continue(|iter| iter.sum::<i32>());
+
+        assert_eq!(None, res);
+    }
 }